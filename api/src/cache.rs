@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+
+/// A pluggable response cache keyed by the fully-rendered request URL.
+/// Implementations only need to be correct about expiry; eviction strategy
+/// beyond that is up to them.
+pub trait Cache: Send + Sync {
+    fn get(&self, key: &str) -> Option<Bytes>;
+    fn put(&self, key: &str, value: Bytes, ttl: Duration);
+}
+
+/// Default in-memory `Cache` backed by a single mutex-guarded map. Entries
+/// past their TTL are treated as absent and lazily evicted on the next
+/// `get`/`put` that touches them.
+#[derive(Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, (Instant, Bytes)>>,
+}
+
+impl InMemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Cache for InMemoryCache {
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((expires_at, value)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn put(&self, key: &str, value: Bytes, ttl: Duration) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(key.to_string(), (Instant::now() + ttl, value));
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::{
+    AppError, DiscoverFilter, DynMovieProvider, Locale, MovieDetails, MovieProvider, Paged,
+    SearchMovie,
+};
+
+/// A small TTL + LRU cache: entries past their TTL are treated as absent,
+/// and once `max_size` is exceeded the least-recently-used entry is evicted.
+struct TtlLruCache<K, V> {
+    ttl: Duration,
+    max_size: usize,
+    state: Mutex<CacheState<K, V>>,
+}
+
+struct CacheState<K, V> {
+    entries: HashMap<K, (Instant, V)>,
+    order: VecDeque<K>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> TtlLruCache<K, V> {
+    fn new(ttl: Duration, max_size: usize) -> Self {
+        Self {
+            ttl,
+            max_size,
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        let mut state = self.state.lock().unwrap();
+        match state.entries.get(key) {
+            Some((inserted_at, _)) if inserted_at.elapsed() > self.ttl => {
+                state.entries.remove(key);
+                state.order.retain(|existing| existing != key);
+                None
+            }
+            Some((_, value)) => {
+                let value = value.clone();
+                state.order.retain(|existing| existing != key);
+                state.order.push_back(key.clone());
+                Some(value)
+            }
+            None => None,
+        }
+    }
+
+    fn put(&self, key: K, value: V) {
+        let mut state = self.state.lock().unwrap();
+        state.order.retain(|existing| existing != &key);
+        state.order.push_back(key.clone());
+        state.entries.insert(key, (Instant::now(), value));
+
+        while state.entries.len() > self.max_size {
+            if let Some(oldest) = state.order.pop_front() {
+                state.entries.remove(&oldest);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+/// Wraps any `MovieProvider` and caches `movie_details`, `search_movies`,
+/// `similar_movies`, and `recommended_movies` in memory, keyed by movie id
+/// (or normalized query) plus the parameters that affect the result.
+/// `discover_movies` is not cached since its filters make keys unbounded.
+pub struct CachingProvider {
+    inner: DynMovieProvider,
+    details: TtlLruCache<String, MovieDetails>,
+    search: TtlLruCache<String, Paged<SearchMovie>>,
+    similar: TtlLruCache<String, Paged<SearchMovie>>,
+    recommended: TtlLruCache<String, Paged<SearchMovie>>,
+}
+
+impl CachingProvider {
+    pub fn new(inner: DynMovieProvider, ttl: Duration, max_size: usize) -> Self {
+        Self {
+            inner,
+            details: TtlLruCache::new(ttl, max_size),
+            search: TtlLruCache::new(ttl, max_size),
+            similar: TtlLruCache::new(ttl, max_size),
+            recommended: TtlLruCache::new(ttl, max_size),
+        }
+    }
+}
+
+fn normalized_key(parts: &[&str]) -> String {
+    parts.join("|")
+}
+
+#[async_trait]
+impl MovieProvider for CachingProvider {
+    async fn search_movies(
+        &self,
+        query: &str,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
+        let page_key = page.to_string();
+        let locale_key = locale.as_ref().map(Locale::as_tmdb_language).unwrap_or_default();
+        let key = normalized_key(&[&query.trim().to_lowercase(), &page_key, &locale_key]);
+
+        if let Some(cached) = self.search.get(&key) {
+            return Ok(cached);
+        }
+
+        let result = self.inner.search_movies(query, page, locale).await?;
+        self.search.put(key, result.clone());
+        Ok(result)
+    }
+
+    async fn movie_details(
+        &self,
+        movie_id: u64,
+        locale: Option<Locale>,
+    ) -> Result<MovieDetails, AppError> {
+        let locale_key = locale.as_ref().map(Locale::as_tmdb_language).unwrap_or_default();
+        let key = normalized_key(&[&movie_id.to_string(), &locale_key]);
+
+        if let Some(cached) = self.details.get(&key) {
+            return Ok(cached);
+        }
+
+        let result = self.inner.movie_details(movie_id, locale).await?;
+        self.details.put(key, result.clone());
+        Ok(result)
+    }
+
+    async fn similar_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
+        let page_key = page.to_string();
+        let locale_key = locale.as_ref().map(Locale::as_tmdb_language).unwrap_or_default();
+        let key = normalized_key(&[&movie_id.to_string(), &page_key, &locale_key]);
+
+        if let Some(cached) = self.similar.get(&key) {
+            return Ok(cached);
+        }
+
+        let result = self.inner.similar_movies(movie_id, page, locale).await?;
+        self.similar.put(key, result.clone());
+        Ok(result)
+    }
+
+    async fn recommended_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
+        let page_key = page.to_string();
+        let locale_key = locale.as_ref().map(Locale::as_tmdb_language).unwrap_or_default();
+        let key = normalized_key(&[&movie_id.to_string(), &page_key, &locale_key]);
+
+        if let Some(cached) = self.recommended.get(&key) {
+            return Ok(cached);
+        }
+
+        let result = self
+            .inner
+            .recommended_movies(movie_id, page, locale)
+            .await?;
+        self.recommended.put(key, result.clone());
+        Ok(result)
+    }
+
+    async fn discover_movies(
+        &self,
+        filter: DiscoverFilter,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
+        self.inner.discover_movies(filter, page, locale).await
+    }
+}
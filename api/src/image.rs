@@ -0,0 +1,232 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::AppError;
+
+const TMDB_IMAGE_BASE: &str = "https://image.tmdb.org/t/p";
+
+/// TMDB's documented image size tokens, narrowed to the ones this crate
+/// actually serves. Keeping it a closed enum means a typo'd size in a
+/// request is a 400, not a silently-broken CDN URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSize {
+    W200,
+    W500,
+    Original,
+}
+
+impl ImageSize {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ImageSize::W200 => "w200",
+            ImageSize::W500 => "w500",
+            ImageSize::Original => "original",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "w200" => Some(ImageSize::W200),
+            "w500" => Some(ImageSize::W500),
+            "original" => Some(ImageSize::Original),
+            _ => None,
+        }
+    }
+}
+
+/// Rejects keys with `..`/`.`/root/prefix components so a crafted `{*path}`
+/// wildcard can't escape the image cache root or the TMDB CDN path prefix.
+/// Leading `/`s (which every TMDB image path has) are stripped first, so a
+/// `/`-rooted key such as `/etc/passwd` is accepted as the relative
+/// `etc/passwd` rather than rejected.
+fn is_path_safe(key: &str) -> bool {
+    Path::new(key.trim_start_matches('/'))
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+}
+
+fn image_url(path: &str, size: ImageSize) -> String {
+    format!(
+        "{TMDB_IMAGE_BASE}/{}/{}",
+        size.as_str(),
+        path.trim_start_matches('/')
+    )
+}
+
+/// Pluggable persistence for downloaded image bytes, keyed by `{size}/{path}`.
+#[async_trait]
+pub trait MediaStore: Send + Sync {
+    async fn read(&self, key: &str) -> Option<Bytes>;
+    async fn write(&self, key: &str, bytes: Bytes);
+}
+
+/// Default `MediaStore` that persists images under a root directory on disk.
+pub struct FilesystemMediaStore {
+    root: PathBuf,
+}
+
+impl FilesystemMediaStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> Option<PathBuf> {
+        if !is_path_safe(key) {
+            return None;
+        }
+        Some(self.root.join(key.trim_start_matches('/')))
+    }
+}
+
+#[async_trait]
+impl MediaStore for FilesystemMediaStore {
+    async fn read(&self, key: &str) -> Option<Bytes> {
+        let path = self.path_for(key)?;
+        tokio::fs::read(path).await.ok().map(Bytes::from)
+    }
+
+    async fn write(&self, key: &str, bytes: Bytes) {
+        let Some(path) = self.path_for(key) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = tokio::fs::create_dir_all(parent).await;
+        }
+        let _ = tokio::fs::write(path, bytes).await;
+    }
+}
+
+/// Fetches TMDB poster/backdrop images through the existing `reqwest::Client`,
+/// keeping the TMDB image host out of client-facing URLs and surviving TMDB
+/// outages for anything already cached.
+pub struct ImageService {
+    client: reqwest::Client,
+    store: Option<Arc<dyn MediaStore>>,
+    max_bytes: u64,
+}
+
+impl ImageService {
+    pub fn new(client: reqwest::Client, store: Option<Arc<dyn MediaStore>>, max_bytes: u64) -> Self {
+        Self {
+            client,
+            store,
+            max_bytes,
+        }
+    }
+
+    pub async fn fetch_image(&self, path: &str, size: ImageSize) -> Result<Bytes, AppError> {
+        if !is_path_safe(path) {
+            return Err(AppError::bad_request(
+                "IMAGE_INVALID_PATH",
+                "Image path is invalid",
+            ));
+        }
+
+        let key = format!("{}/{}", size.as_str(), path.trim_start_matches('/'));
+
+        if let Some(store) = &self.store {
+            if let Some(cached) = store.read(&key).await {
+                return Ok(cached);
+            }
+        }
+
+        let response = self
+            .client
+            .get(image_url(path, size))
+            .send()
+            .await
+            .map_err(|_| AppError::upstream("IMAGE_REQUEST_FAILED", "Failed to fetch image from TMDB"))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::upstream(
+                "IMAGE_UPSTREAM_ERROR",
+                "TMDB image host returned a non-success response",
+            ));
+        }
+
+        if let Some(content_length) = response.content_length() {
+            if content_length > self.max_bytes {
+                return Err(AppError::bad_request(
+                    "IMAGE_TOO_LARGE",
+                    "Image exceeds the configured size limit",
+                ));
+            }
+        }
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|_| AppError::upstream("IMAGE_REQUEST_FAILED", "Failed to read image bytes"))?;
+
+        if bytes.len() as u64 > self.max_bytes {
+            return Err(AppError::bad_request(
+                "IMAGE_TOO_LARGE",
+                "Image exceeds the configured size limit",
+            ));
+        }
+
+        if let Some(store) = &self.store {
+            store.write(&key, bytes.clone()).await;
+        }
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_parent_dir_traversal() {
+        assert!(!is_path_safe("../../etc/passwd"));
+        assert!(!is_path_safe("/poster/../../etc/passwd"));
+    }
+
+    #[test]
+    fn rejects_absolute_paths() {
+        assert!(!is_path_safe("/etc/passwd"));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn rejects_prefix_components() {
+        assert!(!is_path_safe("C:\\Windows\\System32"));
+    }
+
+    #[test]
+    fn accepts_normal_paths() {
+        assert!(is_path_safe("poster.jpg"));
+        assert!(is_path_safe("/abc123/poster.jpg"));
+    }
+
+    #[tokio::test]
+    async fn path_for_rejects_traversal() {
+        let store = FilesystemMediaStore::new(std::env::temp_dir());
+        assert!(store.path_for("../../etc/passwd").is_none());
+    }
+
+    #[tokio::test]
+    async fn path_for_accepts_normal_key() {
+        let root = std::env::temp_dir();
+        let store = FilesystemMediaStore::new(&root);
+        let path = store.path_for("w200/poster.jpg").unwrap();
+        assert_eq!(path, root.join("w200/poster.jpg"));
+    }
+
+    #[tokio::test]
+    async fn fetch_image_rejects_traversal_before_any_request() {
+        let service = ImageService::new(reqwest::Client::new(), None, 10 * 1024 * 1024);
+        let err = service
+            .fetch_image("../../etc/passwd", ImageSize::W200)
+            .await
+            .unwrap_err();
+        match err {
+            AppError::BadRequest { code, .. } => assert_eq!(code, "IMAGE_INVALID_PATH"),
+            other => panic!("expected IMAGE_INVALID_PATH, got {other:?}"),
+        }
+    }
+}
@@ -12,16 +12,42 @@ use futures::stream::{FuturesUnordered, StreamExt};
 use serde::{Deserialize, Serialize};
 use tower_http::cors::{Any, CorsLayer};
 
+pub mod cache;
+pub mod caching_provider;
+pub mod image;
 pub mod tmdb;
+#[cfg(feature = "torrents")]
+pub mod yts;
+
+use image::{ImageService, ImageSize};
 
 pub type DynMovieProvider = Arc<dyn MovieProvider>;
 
+pub type DynTvProvider = Arc<dyn TvProvider>;
+
+pub type DynWatchProvider = Arc<dyn WatchProvider>;
+
+#[cfg(feature = "torrents")]
+pub type DynTorrentProvider = Arc<dyn TorrentProvider>;
+
 #[derive(Clone)]
 pub struct AppState {
     pub provider: DynMovieProvider,
+    pub tv_provider: DynTvProvider,
+    pub image_service: Option<Arc<ImageService>>,
+    pub watch_provider: Option<DynWatchProvider>,
+    #[cfg(feature = "torrents")]
+    pub torrent_provider: Option<DynTorrentProvider>,
 }
 
-pub fn build_router(provider: DynMovieProvider, cors_origin: &str) -> Router {
+pub fn build_router(
+    provider: DynMovieProvider,
+    tv_provider: DynTvProvider,
+    image_service: Option<Arc<ImageService>>,
+    watch_provider: Option<DynWatchProvider>,
+    #[cfg(feature = "torrents")] torrent_provider: Option<DynTorrentProvider>,
+    cors_origin: &str,
+) -> Router {
     let cors = match cors_origin.parse::<HeaderValue>() {
         Ok(origin) => CorsLayer::new()
             .allow_origin(origin)
@@ -33,7 +59,7 @@ pub fn build_router(provider: DynMovieProvider, cors_origin: &str) -> Router {
             .allow_headers(Any),
     };
 
-    Router::new()
+    let router = Router::new()
         .route("/api/health", get(health))
         .route("/api/movies/search", get(search_movies))
         .route("/api/movies/{id}", get(movie_details))
@@ -41,10 +67,41 @@ pub fn build_router(provider: DynMovieProvider, cors_origin: &str) -> Router {
             "/api/movies/{id}/recommendations",
             get(movie_recommendations),
         )
-        .with_state(AppState { provider })
+        .route("/api/images/{size}/{*path}", get(proxy_image))
+        .route("/api/movies/{id}/watch", get(movie_watch_providers))
+        .route("/api/tv/search", get(search_tv))
+        .route("/api/tv/{id}", get(tv_details))
+        .route("/api/tv/{id}/recommendations", get(tv_recommendations))
+        .route("/api/tv/{id}/season/{season_number}", get(tv_season))
+        .route(
+            "/api/tv/{id}/season/{season_number}/episode/{episode_number}",
+            get(tv_episode),
+        );
+
+    #[cfg(feature = "torrents")]
+    let router = router.route("/api/movies/{id}/torrents", get(movie_torrents));
+
+    router
+        .with_state(AppState {
+            provider,
+            tv_provider,
+            image_service,
+            watch_provider,
+            #[cfg(feature = "torrents")]
+            torrent_provider,
+        })
         .layer(cors)
 }
 
+/// Discriminates a result's underlying TMDB catalog, so callers that merge
+/// movie and TV results into one feed can still tell them apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Movie,
+    Tv,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchMovie {
     pub id: u64,
@@ -52,6 +109,23 @@ pub struct SearchMovie {
     pub release_date: Option<String>,
     pub poster_path: Option<String>,
     pub vote_average: f64,
+    pub media_type: MediaType,
+}
+
+/// A single page of a TMDB list endpoint, carrying enough of the envelope
+/// for callers to walk the full result set with repeated calls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paged<T> {
+    pub results: Vec<T>,
+    pub page: u32,
+    pub total_pages: u32,
+    pub total_results: u32,
+}
+
+impl<T> Paged<T> {
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,14 +157,341 @@ pub struct MovieDetails {
     pub director_id: Option<u64>,
     pub cast_ids: Vec<u64>,
     pub genre_ids: Vec<u64>,
+    pub imdb_id: Option<String>,
+}
+
+/// A TMDB locale: an ISO 639-1 language code paired with an ISO 3166-1
+/// region code (e.g. `de-DE`), used to localize titles, overviews, genre
+/// names, and region-appropriate release dates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    language: String,
+    region: String,
+}
+
+impl Locale {
+    /// Validates and builds a locale from separate language/region codes.
+    pub fn new(language: impl AsRef<str>, region: impl AsRef<str>) -> Option<Self> {
+        let language = language.as_ref().to_lowercase();
+        let region = region.as_ref().to_uppercase();
+        let is_alpha2 =
+            |code: &str| code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic());
+        if is_alpha2(&language) && is_alpha2(&region) {
+            Some(Self { language, region })
+        } else {
+            None
+        }
+    }
+
+    /// Parses a TMDB-style `language-REGION` tag (e.g. `de-DE`), case-insensitively.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let (language, region) = tag.split_once('-')?;
+        Self::new(language, region)
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Renders as TMDB's `language` query parameter value, e.g. `de-DE`.
+    pub fn as_tmdb_language(&self) -> String {
+        format!("{}-{}", self.language, self.region)
+    }
+}
+
+impl Default for Locale {
+    /// Falls back to US English, matching TMDB's own default locale.
+    fn default() -> Self {
+        Self::parse("en-US").expect("en-US is a valid locale")
+    }
 }
 
 #[async_trait]
 pub trait MovieProvider: Send + Sync {
-    async fn search_movies(&self, query: &str) -> Result<Vec<SearchMovie>, AppError>;
-    async fn movie_details(&self, movie_id: u64) -> Result<MovieDetails, AppError>;
-    async fn similar_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError>;
-    async fn recommended_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError>;
+    async fn search_movies(
+        &self,
+        query: &str,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError>;
+    async fn movie_details(
+        &self,
+        movie_id: u64,
+        locale: Option<Locale>,
+    ) -> Result<MovieDetails, AppError>;
+    async fn similar_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError>;
+    async fn recommended_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError>;
+    async fn discover_movies(
+        &self,
+        filter: DiscoverFilter,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchTvShow {
+    pub id: u64,
+    pub name: String,
+    pub first_air_date: Option<String>,
+    pub poster_path: Option<String>,
+    pub vote_average: f64,
+    pub media_type: MediaType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvShow {
+    pub id: u64,
+    pub name: String,
+    pub overview: Option<String>,
+    pub first_air_date: Option<String>,
+    pub number_of_seasons: Option<u32>,
+    pub number_of_episodes: Option<u32>,
+    pub episode_run_time: Vec<u32>,
+    pub genres: Vec<Genre>,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    pub vote_average: f64,
+    pub cast: Vec<CastMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSeason {
+    pub id: u64,
+    pub season_number: u32,
+    pub name: String,
+    pub overview: Option<String>,
+    pub air_date: Option<String>,
+    pub episodes: Vec<TvEpisode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvEpisode {
+    pub id: u64,
+    pub episode_number: u32,
+    pub season_number: u32,
+    pub name: String,
+    pub overview: Option<String>,
+    pub air_date: Option<String>,
+    pub vote_average: f64,
+}
+
+/// Mirrors `MovieProvider`'s shape for TV series, which TMDB models as a
+/// distinct set of endpoints rather than a variant of movies.
+#[async_trait]
+pub trait TvProvider: Send + Sync {
+    async fn search_tv(&self, query: &str, page: u32) -> Result<Paged<SearchTvShow>, AppError>;
+    async fn tv_details(&self, tv_id: u64) -> Result<TvShow, AppError>;
+    async fn tv_season(&self, tv_id: u64, season_number: u32) -> Result<TvSeason, AppError>;
+    async fn tv_episode(
+        &self,
+        tv_id: u64,
+        season_number: u32,
+        episode_number: u32,
+    ) -> Result<TvEpisode, AppError>;
+    async fn similar_tv(&self, tv_id: u64, page: u32) -> Result<Paged<SearchTvShow>, AppError>;
+    async fn recommended_tv(&self, tv_id: u64, page: u32) -> Result<Paged<SearchTvShow>, AppError>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchService {
+    pub provider_id: u64,
+    pub provider_name: String,
+    pub logo_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchOptions {
+    pub flatrate: Vec<WatchService>,
+    pub rent: Vec<WatchService>,
+    pub buy: Vec<WatchService>,
+}
+
+/// Per-country "where to watch" availability, keyed by TMDB's ISO 3166-1
+/// country code (e.g. `"US"`).
+pub type WatchAvailability = std::collections::HashMap<String, WatchOptions>;
+
+#[async_trait]
+pub trait WatchProvider: Send + Sync {
+    async fn watch_providers(&self, movie_id: u64) -> Result<WatchAvailability, AppError>;
+}
+
+/// A single downloadable release for a movie, as surfaced by a torrent index.
+#[cfg(feature = "torrents")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Torrent {
+    pub quality: String,
+    pub size: String,
+    pub seeds: u32,
+    pub peers: u32,
+    pub url: String,
+}
+
+/// Looks up torrent availability for a movie by its IMDb id. Kept behind the
+/// `torrents` feature since not every deployment wants to surface this.
+#[cfg(feature = "torrents")]
+#[async_trait]
+pub trait TorrentProvider: Send + Sync {
+    async fn torrents_for_imdb_id(&self, imdb_id: &str) -> Result<Vec<Torrent>, AppError>;
+}
+
+/// Builder for TMDB's `/discover/movie` query parameters. Every field is
+/// optional; only fields that have been set are serialized onto the request.
+#[derive(Debug, Clone, Default)]
+pub struct DiscoverFilter {
+    pub sort_by: Option<String>,
+    pub with_genres: Option<String>,
+    pub without_genres: Option<String>,
+    pub primary_release_year: Option<u32>,
+    pub release_date_gte: Option<String>,
+    pub release_date_lte: Option<String>,
+    pub vote_count_gte: Option<u32>,
+    pub vote_average_gte: Option<f64>,
+    pub vote_average_lte: Option<f64>,
+    pub with_cast: Option<String>,
+    pub with_crew: Option<String>,
+    pub with_keywords: Option<String>,
+    pub region: Option<String>,
+    pub include_adult: Option<bool>,
+}
+
+impl DiscoverFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sort_by(mut self, sort_by: impl Into<String>) -> Self {
+        self.sort_by = Some(sort_by.into());
+        self
+    }
+
+    pub fn with_genres(mut self, with_genres: impl Into<String>) -> Self {
+        self.with_genres = Some(with_genres.into());
+        self
+    }
+
+    pub fn without_genres(mut self, without_genres: impl Into<String>) -> Self {
+        self.without_genres = Some(without_genres.into());
+        self
+    }
+
+    pub fn primary_release_year(mut self, year: u32) -> Self {
+        self.primary_release_year = Some(year);
+        self
+    }
+
+    pub fn release_date_gte(mut self, date: impl Into<String>) -> Self {
+        self.release_date_gte = Some(date.into());
+        self
+    }
+
+    pub fn release_date_lte(mut self, date: impl Into<String>) -> Self {
+        self.release_date_lte = Some(date.into());
+        self
+    }
+
+    pub fn vote_count_gte(mut self, count: u32) -> Self {
+        self.vote_count_gte = Some(count);
+        self
+    }
+
+    pub fn vote_average_gte(mut self, average: f64) -> Self {
+        self.vote_average_gte = Some(average);
+        self
+    }
+
+    pub fn vote_average_lte(mut self, average: f64) -> Self {
+        self.vote_average_lte = Some(average);
+        self
+    }
+
+    pub fn with_cast(mut self, with_cast: impl Into<String>) -> Self {
+        self.with_cast = Some(with_cast.into());
+        self
+    }
+
+    pub fn with_crew(mut self, with_crew: impl Into<String>) -> Self {
+        self.with_crew = Some(with_crew.into());
+        self
+    }
+
+    pub fn with_keywords(mut self, with_keywords: impl Into<String>) -> Self {
+        self.with_keywords = Some(with_keywords.into());
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn include_adult(mut self, include_adult: bool) -> Self {
+        self.include_adult = Some(include_adult);
+        self
+    }
+
+    /// Renders the set fields as `(name, value)` query pairs, in TMDB's
+    /// documented parameter names, omitting anything left unset.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(sort_by) = &self.sort_by {
+            pairs.push(("sort_by", sort_by.clone()));
+        }
+        if let Some(with_genres) = &self.with_genres {
+            pairs.push(("with_genres", with_genres.clone()));
+        }
+        if let Some(without_genres) = &self.without_genres {
+            pairs.push(("without_genres", without_genres.clone()));
+        }
+        if let Some(year) = self.primary_release_year {
+            pairs.push(("primary_release_year", year.to_string()));
+        }
+        if let Some(date) = &self.release_date_gte {
+            pairs.push(("release_date.gte", date.clone()));
+        }
+        if let Some(date) = &self.release_date_lte {
+            pairs.push(("release_date.lte", date.clone()));
+        }
+        if let Some(count) = self.vote_count_gte {
+            pairs.push(("vote_count.gte", count.to_string()));
+        }
+        if let Some(average) = self.vote_average_gte {
+            pairs.push(("vote_average.gte", average.to_string()));
+        }
+        if let Some(average) = self.vote_average_lte {
+            pairs.push(("vote_average.lte", average.to_string()));
+        }
+        if let Some(with_cast) = &self.with_cast {
+            pairs.push(("with_cast", with_cast.clone()));
+        }
+        if let Some(with_crew) = &self.with_crew {
+            pairs.push(("with_crew", with_crew.clone()));
+        }
+        if let Some(with_keywords) = &self.with_keywords {
+            pairs.push(("with_keywords", with_keywords.clone()));
+        }
+        if let Some(region) = &self.region {
+            pairs.push(("region", region.clone()));
+        }
+        if let Some(include_adult) = self.include_adult {
+            pairs.push(("include_adult", include_adult.to_string()));
+        }
+        pairs
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -188,14 +589,33 @@ async fn health() -> Json<HealthResponse> {
     })
 }
 
+/// Parses an optional `?language=language-REGION` query value into a `Locale`.
+fn parse_locale(language: Option<&str>) -> Result<Option<Locale>, AppError> {
+    language
+        .map(|tag| {
+            Locale::parse(tag).ok_or_else(|| {
+                AppError::bad_request(
+                    "INVALID_LANGUAGE",
+                    "language must be a language-REGION tag, e.g. de-DE",
+                )
+            })
+        })
+        .transpose()
+}
+
 #[derive(Debug, Deserialize)]
 struct SearchQuery {
     query: Option<String>,
+    page: Option<u32>,
+    language: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 struct SearchResponse {
     results: Vec<SearchMovie>,
+    page: u32,
+    total_pages: u32,
+    total_results: u32,
 }
 
 async fn search_movies(
@@ -209,8 +629,15 @@ async fn search_movies(
         .filter(|it| !it.is_empty())
         .ok_or_else(|| AppError::bad_request("INVALID_QUERY", "Query parameter is required"))?;
 
-    let results = state.provider.search_movies(title).await?;
-    Ok(Json(SearchResponse { results }))
+    let page = query.page.unwrap_or(1).max(1);
+    let locale = parse_locale(query.language.as_deref())?;
+    let paged = state.provider.search_movies(title, page, locale).await?;
+    Ok(Json(SearchResponse {
+        results: paged.results,
+        page: paged.page,
+        total_pages: paged.total_pages,
+        total_results: paged.total_results,
+    }))
 }
 
 #[derive(Debug, Serialize)]
@@ -235,11 +662,18 @@ struct MovieDetailsCastResponse {
     character: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct LocaleQuery {
+    language: Option<String>,
+}
+
 async fn movie_details(
     State(state): State<AppState>,
     Path(movie_id): Path<u64>,
+    Query(query): Query<LocaleQuery>,
 ) -> Result<Json<MovieDetailsResponse>, AppError> {
-    let details = state.provider.movie_details(movie_id).await?;
+    let locale = parse_locale(query.language.as_deref())?;
+    let details = state.provider.movie_details(movie_id, locale).await?;
 
     Ok(Json(MovieDetailsResponse {
         id: details.id,
@@ -289,15 +723,23 @@ struct RecommendationResult {
 async fn movie_recommendations(
     State(state): State<AppState>,
     Path(movie_id): Path<u64>,
+    Query(query): Query<LocaleQuery>,
 ) -> Result<Json<RecommendationsResponse>, AppError> {
-    let base = state.provider.movie_details(movie_id).await?;
-
-    let similar = state.provider.similar_movies(movie_id).await?;
-    let recommended = state.provider.recommended_movies(movie_id).await?;
+    let locale = parse_locale(query.language.as_deref())?;
+    let base = state.provider.movie_details(movie_id, locale.clone()).await?;
+
+    let similar = state
+        .provider
+        .similar_movies(movie_id, 1, locale.clone())
+        .await?;
+    let recommended = state
+        .provider
+        .recommended_movies(movie_id, 1, locale.clone())
+        .await?;
     let mut seen = HashSet::new();
     let mut candidates = Vec::new();
 
-    for movie in similar.into_iter().chain(recommended.into_iter()) {
+    for movie in similar.results.into_iter().chain(recommended.results.into_iter()) {
         if movie.id == movie_id {
             continue;
         }
@@ -309,28 +751,39 @@ async fn movie_recommendations(
     let mut futures = FuturesUnordered::new();
     for candidate in candidates.into_iter().take(40) {
         let provider = state.provider.clone();
-        futures.push(async move { provider.movie_details(candidate.id).await });
+        let locale = locale.clone();
+        futures.push(async move { provider.movie_details(candidate.id, locale).await });
     }
 
-    let mut results = Vec::new();
+    let mut candidate_details = Vec::new();
     while let Some(candidate) = futures.next().await {
         match candidate {
-            Ok(details) => {
-                let similarity_score = similarity_score(&base, &details);
-                results.push(RecommendationResult {
-                    id: details.id,
-                    title: details.title,
-                    poster_path: details.poster_path,
-                    release_date: details.release_date,
-                    vote_average: details.vote_average,
-                    similarity_score,
-                });
-            }
+            Ok(details) => candidate_details.push(details),
             Err(AppError::NotFound { .. }) => {}
             Err(err) => return Err(err),
         }
     }
 
+    let mut overviews = Vec::with_capacity(candidate_details.len() + 1);
+    overviews.push(base.overview.as_deref());
+    overviews.extend(candidate_details.iter().map(|details| details.overview.as_deref()));
+    let vectors = tfidf_vectors(&overviews);
+    let base_vector = &vectors[0];
+
+    let mut results = Vec::new();
+    for (details, vector) in candidate_details.into_iter().zip(vectors.iter().skip(1)) {
+        let text_score = cosine_similarity(base_vector, vector);
+        let similarity_score = similarity_score(&base, &details, text_score);
+        results.push(RecommendationResult {
+            id: details.id,
+            title: details.title,
+            poster_path: details.poster_path,
+            release_date: details.release_date,
+            vote_average: details.vote_average,
+            similarity_score,
+        });
+    }
+
     results.sort_by(|a, b| {
         b.similarity_score
             .partial_cmp(&a.similarity_score)
@@ -347,7 +800,7 @@ async fn movie_recommendations(
     }))
 }
 
-fn similarity_score(base: &MovieDetails, candidate: &MovieDetails) -> f64 {
+fn similarity_score(base: &MovieDetails, candidate: &MovieDetails, text_score: f64) -> f64 {
     let base_genres: HashSet<u64> = base.genre_ids.iter().copied().collect();
     let cand_genres: HashSet<u64> = candidate.genre_ids.iter().copied().collect();
     let base_cast: HashSet<u64> = base.cast_ids.iter().copied().collect();
@@ -378,10 +831,314 @@ fn similarity_score(base: &MovieDetails, candidate: &MovieDetails) -> f64 {
         (1.0 - (diff / 5.0)).clamp(0.0, 1.0)
     };
 
-    let total = genre_score * 0.45 + director_score * 0.2 + cast_score * 0.2 + rating_score * 0.15;
+    let total = genre_score * 0.35
+        + director_score * 0.15
+        + cast_score * 0.15
+        + rating_score * 0.10
+        + text_score * 0.25;
     (total * 1000.0).round() / 10.0
 }
 
+/// A small English stopword list for overview tokenization; not exhaustive,
+/// just enough to keep the most common filler words out of the TF-IDF vectors.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "but", "of", "to", "in", "on", "at", "for", "with", "is", "are",
+    "was", "were", "be", "been", "being", "it", "its", "this", "that", "as", "by", "from", "his",
+    "her", "their", "he", "she", "they", "who", "when", "will", "has", "have", "had",
+];
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty() && !STOPWORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Builds a TF-IDF vector per document (in input order), treating the whole
+/// slice as the corpus for document-frequency purposes. Missing or empty
+/// overviews tokenize to an empty vector, which yields 0 cosine similarity.
+fn tfidf_vectors(overviews: &[Option<&str>]) -> Vec<std::collections::HashMap<String, f64>> {
+    let docs: Vec<Vec<String>> = overviews
+        .iter()
+        .map(|overview| tokenize(overview.unwrap_or("")))
+        .collect();
+    let doc_count = docs.len() as f64;
+
+    let mut document_frequency: std::collections::HashMap<&str, usize> =
+        std::collections::HashMap::new();
+    for doc in &docs {
+        let unique_terms: HashSet<&str> = doc.iter().map(String::as_str).collect();
+        for term in unique_terms {
+            *document_frequency.entry(term).or_insert(0) += 1;
+        }
+    }
+
+    docs.iter()
+        .map(|doc| {
+            let mut term_counts: std::collections::HashMap<&str, usize> =
+                std::collections::HashMap::new();
+            for term in doc {
+                *term_counts.entry(term.as_str()).or_insert(0) += 1;
+            }
+
+            term_counts
+                .into_iter()
+                .map(|(term, count)| {
+                    let df = document_frequency.get(term).copied().unwrap_or(0) as f64;
+                    let weight = count as f64 * ((doc_count + 1.0) / (df + 1.0)).ln() + 1.0;
+                    (term.to_string(), weight)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Cosine similarity between two TF-IDF vectors; 0 when either vector has no
+/// magnitude (e.g. an empty or missing overview).
+fn cosine_similarity(
+    a: &std::collections::HashMap<String, f64>,
+    b: &std::collections::HashMap<String, f64>,
+) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other_weight| weight * other_weight))
+        .sum();
+    let norm_a = a.values().map(|weight| weight * weight).sum::<f64>().sqrt();
+    let norm_b = b.values().map(|weight| weight * weight).sum::<f64>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TvSearchQuery {
+    query: Option<String>,
+    page: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+struct TvSearchResponse {
+    results: Vec<SearchTvShow>,
+    page: u32,
+    total_pages: u32,
+    total_results: u32,
+}
+
+async fn search_tv(
+    State(state): State<AppState>,
+    Query(query): Query<TvSearchQuery>,
+) -> Result<Json<TvSearchResponse>, AppError> {
+    let title = query
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|it| !it.is_empty())
+        .ok_or_else(|| AppError::bad_request("INVALID_QUERY", "Query parameter is required"))?;
+
+    let page = query.page.unwrap_or(1).max(1);
+    let paged = state.tv_provider.search_tv(title, page).await?;
+    Ok(Json(TvSearchResponse {
+        results: paged.results,
+        page: paged.page,
+        total_pages: paged.total_pages,
+        total_results: paged.total_results,
+    }))
+}
+
+async fn tv_details(
+    State(state): State<AppState>,
+    Path(tv_id): Path<u64>,
+) -> Result<Json<TvShow>, AppError> {
+    let details = state.tv_provider.tv_details(tv_id).await?;
+    Ok(Json(details))
+}
+
+async fn tv_season(
+    State(state): State<AppState>,
+    Path((tv_id, season_number)): Path<(u64, u32)>,
+) -> Result<Json<TvSeason>, AppError> {
+    let season = state.tv_provider.tv_season(tv_id, season_number).await?;
+    Ok(Json(season))
+}
+
+async fn tv_episode(
+    State(state): State<AppState>,
+    Path((tv_id, season_number, episode_number)): Path<(u64, u32, u32)>,
+) -> Result<Json<TvEpisode>, AppError> {
+    let episode = state
+        .tv_provider
+        .tv_episode(tv_id, season_number, episode_number)
+        .await?;
+    Ok(Json(episode))
+}
+
+#[derive(Debug, Serialize)]
+struct TvRecommendationsResponse {
+    base_show: TvRecommendationBaseShow,
+    results: Vec<TvRecommendationResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct TvRecommendationBaseShow {
+    id: u64,
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct TvRecommendationResult {
+    id: u64,
+    name: String,
+    poster_path: Option<String>,
+    first_air_date: Option<String>,
+    vote_average: f64,
+    similarity_score: f64,
+}
+
+async fn tv_recommendations(
+    State(state): State<AppState>,
+    Path(tv_id): Path<u64>,
+) -> Result<Json<TvRecommendationsResponse>, AppError> {
+    let base = state.tv_provider.tv_details(tv_id).await?;
+
+    let similar = state.tv_provider.similar_tv(tv_id, 1).await?;
+    let recommended = state.tv_provider.recommended_tv(tv_id, 1).await?;
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
+
+    for show in similar.results.into_iter().chain(recommended.results.into_iter()) {
+        if show.id == tv_id {
+            continue;
+        }
+        if seen.insert(show.id) {
+            candidates.push(show);
+        }
+    }
+
+    let mut futures = FuturesUnordered::new();
+    for candidate in candidates.into_iter().take(40) {
+        let tv_provider = state.tv_provider.clone();
+        futures.push(async move { tv_provider.tv_details(candidate.id).await });
+    }
+
+    let mut results = Vec::new();
+    while let Some(candidate) = futures.next().await {
+        match candidate {
+            Ok(details) => {
+                let similarity_score = tv_similarity_score(&base, &details);
+                results.push(TvRecommendationResult {
+                    id: details.id,
+                    name: details.name,
+                    poster_path: details.poster_path,
+                    first_air_date: details.first_air_date,
+                    vote_average: details.vote_average,
+                    similarity_score,
+                });
+            }
+            Err(AppError::NotFound { .. }) => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    results.sort_by(|a, b| {
+        b.similarity_score
+            .partial_cmp(&a.similarity_score)
+            .unwrap_or(Ordering::Equal)
+    });
+    results.truncate(20);
+
+    Ok(Json(TvRecommendationsResponse {
+        base_show: TvRecommendationBaseShow {
+            id: base.id,
+            name: base.name,
+        },
+        results,
+    }))
+}
+
+/// Lighter-weight sibling of `similarity_score` for TV: `TvShow` has no
+/// director/cast-id fields to compare, so this blends genre overlap with
+/// rating proximity only.
+fn tv_similarity_score(base: &TvShow, candidate: &TvShow) -> f64 {
+    let base_genres: HashSet<u64> = base.genres.iter().map(|genre| genre.id).collect();
+    let cand_genres: HashSet<u64> = candidate.genres.iter().map(|genre| genre.id).collect();
+
+    let genre_score = if base_genres.is_empty() || cand_genres.is_empty() {
+        0.0
+    } else {
+        let shared = base_genres.intersection(&cand_genres).count() as f64;
+        let union = base_genres.union(&cand_genres).count() as f64;
+        if union == 0.0 { 0.0 } else { shared / union }
+    };
+
+    let rating_score = {
+        let diff = (base.vote_average - candidate.vote_average).abs();
+        (1.0 - (diff / 5.0)).clamp(0.0, 1.0)
+    };
+
+    let total = genre_score * 0.7 + rating_score * 0.3;
+    (total * 1000.0).round() / 10.0
+}
+
+async fn proxy_image(
+    State(state): State<AppState>,
+    Path((size, path)): Path<(String, String)>,
+) -> Result<Response, AppError> {
+    let service = state.image_service.as_ref().ok_or_else(|| {
+        AppError::internal("IMAGE_PROXY_DISABLED", "Image proxying is not configured")
+    })?;
+
+    let size = ImageSize::parse(&size)
+        .ok_or_else(|| AppError::bad_request("INVALID_IMAGE_SIZE", "Unsupported image size"))?;
+
+    let bytes = service.fetch_image(&path, size).await?;
+    Ok(([(axum::http::header::CONTENT_TYPE, "image/jpeg")], bytes).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+struct WatchQuery {
+    region: Option<String>,
+}
+
+async fn movie_watch_providers(
+    State(state): State<AppState>,
+    Path(movie_id): Path<u64>,
+    Query(query): Query<WatchQuery>,
+) -> Result<Json<WatchAvailability>, AppError> {
+    let watch_provider = state.watch_provider.as_ref().ok_or_else(|| {
+        AppError::internal("WATCH_PROVIDER_DISABLED", "Watch availability is not configured")
+    })?;
+
+    let mut availability = watch_provider.watch_providers(movie_id).await?;
+
+    if let Some(region) = query.region.as_deref().map(str::to_uppercase) {
+        availability.retain(|country, _| *country == region);
+    }
+
+    Ok(Json(availability))
+}
+
+#[cfg(feature = "torrents")]
+async fn movie_torrents(
+    State(state): State<AppState>,
+    Path(movie_id): Path<u64>,
+) -> Result<Json<Vec<Torrent>>, AppError> {
+    let torrent_provider = state.torrent_provider.as_ref().ok_or_else(|| {
+        AppError::internal("TORRENT_PROVIDER_DISABLED", "Torrent availability is not configured")
+    })?;
+
+    let details = state.provider.movie_details(movie_id, None).await?;
+    let imdb_id = details.imdb_id.ok_or_else(|| {
+        AppError::not_found("IMDB_ID_MISSING", "No IMDb id is available for this movie")
+    })?;
+
+    let torrents = torrent_provider.torrents_for_imdb_id(&imdb_id).await?;
+    Ok(Json(torrents))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,33 +1154,131 @@ mod tests {
         recommended: std::collections::HashMap<u64, Vec<SearchMovie>>,
     }
 
+    fn single_page(results: Vec<SearchMovie>) -> Paged<SearchMovie> {
+        Paged {
+            total_results: results.len() as u32,
+            results,
+            page: 1,
+            total_pages: 1,
+        }
+    }
+
     #[async_trait]
     impl MovieProvider for MockProvider {
-        async fn search_movies(&self, _query: &str) -> Result<Vec<SearchMovie>, AppError> {
-            Ok(self.search.clone())
+        async fn search_movies(
+            &self,
+            _query: &str,
+            _page: u32,
+            _locale: Option<Locale>,
+        ) -> Result<Paged<SearchMovie>, AppError> {
+            Ok(single_page(self.search.clone()))
         }
 
-        async fn movie_details(&self, movie_id: u64) -> Result<MovieDetails, AppError> {
+        async fn movie_details(
+            &self,
+            movie_id: u64,
+            _locale: Option<Locale>,
+        ) -> Result<MovieDetails, AppError> {
             self.details
                 .get(&movie_id)
                 .cloned()
                 .ok_or_else(|| AppError::not_found("MOVIE_NOT_FOUND", "Movie not found"))
         }
 
-        async fn similar_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError> {
-            Ok(self.similar.get(&movie_id).cloned().unwrap_or_default())
+        async fn similar_movies(
+            &self,
+            movie_id: u64,
+            _page: u32,
+            _locale: Option<Locale>,
+        ) -> Result<Paged<SearchMovie>, AppError> {
+            Ok(single_page(
+                self.similar.get(&movie_id).cloned().unwrap_or_default(),
+            ))
+        }
+
+        async fn recommended_movies(
+            &self,
+            movie_id: u64,
+            _page: u32,
+            _locale: Option<Locale>,
+        ) -> Result<Paged<SearchMovie>, AppError> {
+            Ok(single_page(
+                self.recommended.get(&movie_id).cloned().unwrap_or_default(),
+            ))
+        }
+
+        async fn discover_movies(
+            &self,
+            _filter: DiscoverFilter,
+            _page: u32,
+            _locale: Option<Locale>,
+        ) -> Result<Paged<SearchMovie>, AppError> {
+            Ok(single_page(self.search.clone()))
+        }
+    }
+
+    #[async_trait]
+    impl TvProvider for MockProvider {
+        async fn search_tv(&self, _query: &str, _page: u32) -> Result<Paged<SearchTvShow>, AppError> {
+            Ok(Paged {
+                total_results: 0,
+                results: Vec::new(),
+                page: 1,
+                total_pages: 1,
+            })
+        }
+
+        async fn tv_details(&self, _tv_id: u64) -> Result<TvShow, AppError> {
+            Err(AppError::not_found("TV_SHOW_NOT_FOUND", "TV show not found"))
+        }
+
+        async fn tv_season(&self, _tv_id: u64, _season_number: u32) -> Result<TvSeason, AppError> {
+            Err(AppError::not_found("TV_SEASON_NOT_FOUND", "TV season not found"))
+        }
+
+        async fn tv_episode(
+            &self,
+            _tv_id: u64,
+            _season_number: u32,
+            _episode_number: u32,
+        ) -> Result<TvEpisode, AppError> {
+            Err(AppError::not_found("TV_EPISODE_NOT_FOUND", "TV episode not found"))
         }
 
-        async fn recommended_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError> {
-            Ok(self.recommended.get(&movie_id).cloned().unwrap_or_default())
+        async fn similar_tv(&self, _tv_id: u64, _page: u32) -> Result<Paged<SearchTvShow>, AppError> {
+            Ok(Paged {
+                total_results: 0,
+                results: Vec::new(),
+                page: 1,
+                total_pages: 1,
+            })
+        }
+
+        async fn recommended_tv(
+            &self,
+            _tv_id: u64,
+            _page: u32,
+        ) -> Result<Paged<SearchTvShow>, AppError> {
+            Ok(Paged {
+                total_results: 0,
+                results: Vec::new(),
+                page: 1,
+                total_pages: 1,
+            })
         }
     }
 
-    fn make_movie(id: u64, title: &str, genres: &[u64], director_id: Option<u64>) -> MovieDetails {
+    fn make_movie(
+        id: u64,
+        title: &str,
+        overview: &str,
+        genres: &[u64],
+        director_id: Option<u64>,
+    ) -> MovieDetails {
         MovieDetails {
             id,
             title: title.to_string(),
-            overview: Some("Overview".to_string()),
+            overview: Some(overview.to_string()),
             release_date: Some("2010-07-15".to_string()),
             runtime: Some(120),
             genres: genres
@@ -445,12 +1300,21 @@ mod tests {
             director_id,
             cast_ids: vec![1, 2, 3],
             genre_ids: genres.to_vec(),
+            imdb_id: None,
         }
     }
 
     #[tokio::test]
     async fn health_endpoint_returns_ok() {
-        let app = build_router(Arc::new(MockProvider::default()), "http://localhost:5173");
+        let app = build_router(
+            Arc::new(MockProvider::default()),
+            Arc::new(MockProvider::default()),
+            None,
+            None,
+            #[cfg(feature = "torrents")]
+            None,
+            "http://localhost:5173",
+        );
         let response = app
             .oneshot(
                 Request::builder()
@@ -470,7 +1334,15 @@ mod tests {
 
     #[tokio::test]
     async fn search_endpoint_requires_query() {
-        let app = build_router(Arc::new(MockProvider::default()), "http://localhost:5173");
+        let app = build_router(
+            Arc::new(MockProvider::default()),
+            Arc::new(MockProvider::default()),
+            None,
+            None,
+            #[cfg(feature = "torrents")]
+            None,
+            "http://localhost:5173",
+        );
         let response = app
             .oneshot(
                 Request::builder()
@@ -496,10 +1368,19 @@ mod tests {
                 release_date: Some("2010-07-15".to_string()),
                 poster_path: Some("/poster.jpg".to_string()),
                 vote_average: 8.4,
+                media_type: MediaType::Movie,
             }],
             ..MockProvider::default()
         };
-        let app = build_router(Arc::new(provider), "http://localhost:5173");
+        let app = build_router(
+            Arc::new(provider),
+            Arc::new(MockProvider::default()),
+            None,
+            None,
+            #[cfg(feature = "torrents")]
+            None,
+            "http://localhost:5173",
+        );
         let response = app
             .oneshot(
                 Request::builder()
@@ -518,7 +1399,15 @@ mod tests {
 
     #[tokio::test]
     async fn movie_details_returns_not_found() {
-        let app = build_router(Arc::new(MockProvider::default()), "http://localhost:5173");
+        let app = build_router(
+            Arc::new(MockProvider::default()),
+            Arc::new(MockProvider::default()),
+            None,
+            None,
+            #[cfg(feature = "torrents")]
+            None,
+            "http://localhost:5173",
+        );
         let response = app
             .oneshot(
                 Request::builder()
@@ -544,15 +1433,33 @@ mod tests {
         let mut details = std::collections::HashMap::new();
         details.insert(
             base_movie_id,
-            make_movie(base_movie_id, "Base", &[28, 878], Some(99)),
+            make_movie(
+                base_movie_id,
+                "Base",
+                "A dream thief enters a heist to plant an idea in a target's mind.",
+                &[28, 878],
+                Some(99),
+            ),
         );
         details.insert(
             candidate_high_id,
-            make_movie(candidate_high_id, "High", &[28, 878], Some(99)),
+            make_movie(
+                candidate_high_id,
+                "High",
+                "A thief enters a dream to steal an idea during a heist.",
+                &[28, 878],
+                Some(99),
+            ),
         );
         details.insert(
             candidate_low_id,
-            make_movie(candidate_low_id, "Low", &[35], Some(12)),
+            make_movie(
+                candidate_low_id,
+                "Low",
+                "A romantic comedy about two chefs who fall in love.",
+                &[35],
+                Some(12),
+            ),
         );
 
         let provider = MockProvider {
@@ -566,6 +1473,7 @@ mod tests {
                         release_date: Some("2014-11-05".to_string()),
                         poster_path: Some("/high.jpg".to_string()),
                         vote_average: 8.3,
+                        media_type: MediaType::Movie,
                     },
                     SearchMovie {
                         id: candidate_low_id,
@@ -573,13 +1481,22 @@ mod tests {
                         release_date: Some("2000-01-01".to_string()),
                         poster_path: Some("/low.jpg".to_string()),
                         vote_average: 6.0,
+                        media_type: MediaType::Movie,
                     },
                 ],
             )]),
             ..MockProvider::default()
         };
 
-        let app = build_router(Arc::new(provider), "http://localhost:5173");
+        let app = build_router(
+            Arc::new(provider),
+            Arc::new(MockProvider::default()),
+            None,
+            None,
+            #[cfg(feature = "torrents")]
+            None,
+            "http://localhost:5173",
+        );
         let response = app
             .oneshot(
                 Request::builder()
@@ -597,4 +1514,38 @@ mod tests {
         assert_eq!(json["results"][0]["title"], "High");
         assert!(json["results"][0]["similarity_score"].as_f64().unwrap() > 0.0);
     }
+
+    #[test]
+    fn cosine_similarity_ranks_closer_overview_higher() {
+        let overviews = [
+            Some("A dream thief enters a heist to plant an idea in a target's mind."),
+            Some("A thief enters a dream to steal an idea during a heist."),
+            Some("A romantic comedy about two chefs who fall in love."),
+        ];
+        let vectors = tfidf_vectors(&overviews);
+
+        let close_score = cosine_similarity(&vectors[0], &vectors[1]);
+        let far_score = cosine_similarity(&vectors[0], &vectors[2]);
+
+        assert!(
+            close_score > far_score,
+            "expected overlapping overviews to score higher ({close_score} <= {far_score})"
+        );
+    }
+
+    #[test]
+    fn cosine_similarity_is_zero_for_missing_overview() {
+        let overviews = [Some("A dream thief enters a heist."), None];
+        let vectors = tfidf_vectors(&overviews);
+
+        assert_eq!(cosine_similarity(&vectors[0], &vectors[1]), 0.0);
+    }
+
+    #[test]
+    fn cosine_similarity_is_zero_for_empty_overview() {
+        let overviews = [Some("A dream thief enters a heist."), Some("")];
+        let vectors = tfidf_vectors(&overviews);
+
+        assert_eq!(cosine_similarity(&vectors[0], &vectors[1]), 0.0);
+    }
 }
@@ -2,9 +2,19 @@ use std::env;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use api::caching_provider::CachingProvider;
+use api::image::{FilesystemMediaStore, ImageService};
 use api::{build_router, tmdb::TmdbProvider};
+#[cfg(feature = "torrents")]
+use api::yts::YtsProvider;
 use tracing::info;
 
+const DEFAULT_IMAGE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_PROVIDER_CACHE_TTL_SECS: u64 = 300;
+const DEFAULT_PROVIDER_CACHE_MAX_SIZE: usize = 1000;
+const DEFAULT_TMDB_MAX_ATTEMPTS: u32 = 5;
+const DEFAULT_TMDB_RETRY_BASE_DELAY_MS: u64 = 200;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt()
@@ -21,14 +31,75 @@ async fn main() -> anyhow::Result<()> {
         env::var("TMDB_BASE_URL").unwrap_or_else(|_| "https://api.themoviedb.org/3".to_string());
     let tmdb_access_token = env::var("TMDB_ACCESS_TOKEN").ok();
     let tmdb_api_key = env::var("TMDB_API_KEY").ok();
+    let tmdb_default_language = env::var("TMDB_DEFAULT_LANGUAGE").ok();
+    let tmdb_default_region = env::var("TMDB_DEFAULT_REGION").ok();
+    let tmdb_max_attempts = env::var("TMDB_MAX_ATTEMPTS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_TMDB_MAX_ATTEMPTS);
+    let tmdb_retry_base_delay = env::var("TMDB_RETRY_BASE_DELAY_MS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .map(std::time::Duration::from_millis)
+        .unwrap_or(std::time::Duration::from_millis(DEFAULT_TMDB_RETRY_BASE_DELAY_MS));
+
+    let provider = Arc::new(
+        TmdbProvider::new(
+            tmdb_base_url,
+            tmdb_access_token,
+            tmdb_api_key,
+            Some(Arc::new(api::cache::InMemoryCache::new())),
+            tmdb_default_language,
+            tmdb_default_region,
+        )
+        .with_retry_policy(tmdb_max_attempts, tmdb_retry_base_delay),
+    );
+
+    let image_cache_dir = env::var("IMAGE_CACHE_DIR").ok();
+    let image_max_bytes = env::var("IMAGE_MAX_BYTES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_IMAGE_MAX_BYTES);
+    let image_service = Some(Arc::new(ImageService::new(
+        reqwest::Client::new(),
+        image_cache_dir.map(|dir| Arc::new(FilesystemMediaStore::new(dir)) as _),
+        image_max_bytes,
+    )));
+
+    let watch_provider: Option<api::DynWatchProvider> = Some(provider.clone());
+    let tv_provider: api::DynTvProvider = provider.clone();
+
+    #[cfg(feature = "torrents")]
+    let torrent_provider: Option<api::DynTorrentProvider> = {
+        let yts_base_url =
+            env::var("YTS_BASE_URL").unwrap_or_else(|_| "https://yts.mx".to_string());
+        Some(Arc::new(YtsProvider::new(yts_base_url)))
+    };
 
-    let provider = Arc::new(TmdbProvider::new(
-        tmdb_base_url,
-        tmdb_access_token,
-        tmdb_api_key,
+    let provider_cache_ttl = env::var("PROVIDER_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(std::time::Duration::from_secs(DEFAULT_PROVIDER_CACHE_TTL_SECS));
+    let provider_cache_max_size = env::var("PROVIDER_CACHE_MAX_SIZE")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_PROVIDER_CACHE_MAX_SIZE);
+    let provider: api::DynMovieProvider = Arc::new(CachingProvider::new(
+        provider,
+        provider_cache_ttl,
+        provider_cache_max_size,
     ));
 
-    let app = build_router(provider, &cors_origin);
+    let app = build_router(
+        provider,
+        tv_provider,
+        image_service,
+        watch_provider,
+        #[cfg(feature = "torrents")]
+        torrent_provider,
+        &cors_origin,
+    );
     let addr: SocketAddr = bind_addr.parse()?;
     let listener = tokio::net::TcpListener::bind(addr).await?;
 
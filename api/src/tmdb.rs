@@ -1,9 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use async_trait::async_trait;
 use reqwest::StatusCode;
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
 
-use crate::{AppError, CastMember, Genre, MovieDetails, MovieProvider, SearchMovie};
+use crate::cache::Cache;
+use crate::{
+    AppError, CastMember, DiscoverFilter, Genre, Locale, MovieDetails, MovieProvider, Paged,
+    SearchMovie,
+};
+
+/// Details/credits/similar/recommendations rarely change; cache them for a while.
+const LONG_CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Search and discover results shift as TMDB's catalog and popularity ranks move.
+const SHORT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+/// Upper bound on any single retry sleep, including a TMDB-supplied
+/// `Retry-After`, so one misbehaving response can't stall a request for minutes.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
 #[derive(Clone)]
 pub struct TmdbProvider {
@@ -11,18 +29,42 @@ pub struct TmdbProvider {
     base_url: String,
     access_token: Option<String>,
     api_key: Option<String>,
+    cache: Option<Arc<dyn Cache>>,
+    max_attempts: u32,
+    retry_base_delay: Duration,
+    default_language: Option<String>,
+    default_region: Option<String>,
 }
 
 impl TmdbProvider {
-    pub fn new(base_url: String, access_token: Option<String>, api_key: Option<String>) -> Self {
+    pub fn new(
+        base_url: String,
+        access_token: Option<String>,
+        api_key: Option<String>,
+        cache: Option<Arc<dyn Cache>>,
+        default_language: Option<String>,
+        default_region: Option<String>,
+    ) -> Self {
         Self {
             client: reqwest::Client::new(),
             base_url,
             access_token,
             api_key,
+            cache,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            default_language,
+            default_region,
         }
     }
 
+    /// Overrides the default retry policy (5 attempts, 200ms base delay).
+    pub fn with_retry_policy(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_base_delay = base_delay;
+        self
+    }
+
     fn ensure_auth_configured(&self) -> Result<(), AppError> {
         if self.access_token.is_none() && self.api_key.is_none() {
             return Err(AppError::unauthorized(
@@ -33,6 +75,30 @@ impl TmdbProvider {
         Ok(())
     }
 
+    /// Resolves the locale for a single call: an explicit per-call override
+    /// wins, then the provider's configured default language and/or region
+    /// (independently, each falling back to TMDB's own `en`/`US` default),
+    /// then TMDB's own default locale.
+    fn resolve_locale(&self, locale: Option<Locale>) -> Locale {
+        locale.unwrap_or_else(|| {
+            let default = Locale::default();
+            let language = self.default_language.as_deref().unwrap_or(default.language());
+            let region = self.default_region.as_deref().unwrap_or(default.region());
+            Locale::new(language, region).unwrap_or(default)
+        })
+    }
+
+    /// The `region` query param, distinct from `resolve_locale`'s language
+    /// tag: only set when a region was actually configured or requested, so
+    /// endpoints that care about release-date region don't get a synthesized
+    /// `US` when nobody asked for one.
+    fn resolved_region(&self, locale: &Option<Locale>) -> Option<String> {
+        locale
+            .as_ref()
+            .map(|locale| locale.region().to_string())
+            .or_else(|| self.default_region.clone())
+    }
+
     fn get(&self, path: &str) -> reqwest::RequestBuilder {
         let url = format!(
             "{}/{}",
@@ -54,60 +120,160 @@ impl TmdbProvider {
     async fn send_json<T: DeserializeOwned>(
         &self,
         req: reqwest::RequestBuilder,
+        ttl: Duration,
     ) -> Result<T, AppError> {
-        let response = req.send().await.map_err(|_| {
-            AppError::upstream("TMDB_REQUEST_FAILED", "Failed to send request to TMDB")
-        })?;
-
-        match response.status() {
-            StatusCode::OK => response.json::<T>().await.map_err(|_| {
-                AppError::upstream("TMDB_DECODE_FAILED", "Failed to decode TMDB response")
-            }),
-            StatusCode::NOT_FOUND => Err(AppError::not_found("MOVIE_NOT_FOUND", "Movie not found")),
-            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Err(AppError::unauthorized(
-                "TMDB_UNAUTHORIZED",
-                "TMDB credentials are invalid",
-            )),
-            _ => Err(AppError::upstream(
-                "TMDB_UPSTREAM_ERROR",
-                "TMDB returned a non-success response",
-            )),
+        let cache_key = req
+            .try_clone()
+            .and_then(|clone| clone.build().ok())
+            .map(|built| built.url().to_string());
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(body) = cache.get(key) {
+                return serde_json::from_slice(&body).map_err(|_| {
+                    AppError::upstream("TMDB_DECODE_FAILED", "Failed to decode TMDB response")
+                });
+            }
         }
+
+        let mut delay = self.retry_base_delay;
+
+        for attempt in 1..=self.max_attempts {
+            let attempt_req = req.try_clone().ok_or_else(|| {
+                AppError::internal("TMDB_REQUEST_CLONE_FAILED", "Failed to prepare TMDB request")
+            })?;
+
+            let response = match attempt_req.send().await {
+                Ok(response) => response,
+                Err(_) if attempt < self.max_attempts => {
+                    tokio::time::sleep(jittered(delay).min(MAX_RETRY_DELAY)).await;
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                    continue;
+                }
+                Err(_) => {
+                    return Err(AppError::upstream(
+                        "TMDB_REQUEST_FAILED",
+                        "Failed to send request to TMDB",
+                    ));
+                }
+            };
+
+            match response.status() {
+                StatusCode::OK => {
+                    let body = response.bytes().await.map_err(|_| {
+                        AppError::upstream("TMDB_DECODE_FAILED", "Failed to decode TMDB response")
+                    })?;
+
+                    if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+                        cache.put(key, body.clone(), ttl);
+                    }
+
+                    return serde_json::from_slice(&body).map_err(|_| {
+                        AppError::upstream("TMDB_DECODE_FAILED", "Failed to decode TMDB response")
+                    });
+                }
+                StatusCode::NOT_FOUND => {
+                    return Err(AppError::not_found("MOVIE_NOT_FOUND", "Movie not found"));
+                }
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    return Err(AppError::unauthorized(
+                        "TMDB_UNAUTHORIZED",
+                        "TMDB credentials are invalid",
+                    ));
+                }
+                StatusCode::TOO_MANY_REQUESTS if attempt < self.max_attempts => {
+                    let retry_after = response
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok())
+                        .and_then(|value| value.parse::<u64>().ok())
+                        .map(Duration::from_secs)
+                        .unwrap_or(delay)
+                        .min(MAX_RETRY_DELAY);
+                    tokio::time::sleep(retry_after).await;
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                }
+                status if status.is_server_error() && attempt < self.max_attempts => {
+                    tokio::time::sleep(jittered(delay).min(MAX_RETRY_DELAY)).await;
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                }
+                _ => {
+                    return Err(AppError::upstream(
+                        "TMDB_UPSTREAM_ERROR",
+                        "TMDB returned a non-success response",
+                    ));
+                }
+            }
+        }
+
+        Err(AppError::upstream(
+            "TMDB_UPSTREAM_ERROR",
+            "TMDB returned a non-success response",
+        ))
+    }
+}
+
+/// `send_json` always maps a 404 to a generic "movie not found"; callers for
+/// other entity types (TV shows, seasons, episodes) remap it to their own
+/// not-found code/message so the error actually names what was missing.
+fn remap_not_found(err: AppError, code: &str, message: &str) -> AppError {
+    match err {
+        AppError::NotFound { .. } => AppError::not_found(code, message),
+        other => other,
     }
 }
 
+/// Adds +/-50% jitter to a backoff delay so concurrent retries don't thunder
+/// against TMDB in lockstep.
+fn jittered(delay: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.subsec_nanos())
+        .unwrap_or(0);
+    let factor = 0.5 + (nanos % 1000) as f64 / 1000.0;
+    Duration::from_secs_f64(delay.as_secs_f64() * factor)
+}
+
 #[async_trait]
 impl MovieProvider for TmdbProvider {
-    async fn search_movies(&self, query: &str) -> Result<Vec<SearchMovie>, AppError> {
+    async fn search_movies(
+        &self,
+        query: &str,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
         self.ensure_auth_configured()?;
-        let response = self
-            .send_json::<TmdbListResponse>(self.get("/search/movie").query(&[
-                ("query", query),
-                ("include_adult", "false"),
-                ("language", "en-US"),
-            ]))
-            .await?;
+        let page = page.to_string();
+        let region = self.resolved_region(&locale);
+        let language = self.resolve_locale(locale).as_tmdb_language();
+        let mut req = self.get("/search/movie").query(&[
+            ("query", query),
+            ("include_adult", "false"),
+            ("language", language.as_str()),
+            ("page", page.as_str()),
+        ]);
+        if let Some(region) = &region {
+            req = req.query(&[("region", region.as_str())]);
+        }
+        let response = self.send_json::<TmdbListResponse>(req, SHORT_CACHE_TTL).await?;
 
-        Ok(response
-            .results
-            .into_iter()
-            .map(|item| SearchMovie {
-                id: item.id,
-                title: item.title,
-                release_date: item.release_date,
-                poster_path: item.poster_path,
-                vote_average: item.vote_average.unwrap_or_default(),
-            })
-            .collect())
+        Ok(response.into_paged())
     }
 
-    async fn movie_details(&self, movie_id: u64) -> Result<MovieDetails, AppError> {
+    async fn movie_details(
+        &self,
+        movie_id: u64,
+        locale: Option<Locale>,
+    ) -> Result<MovieDetails, AppError> {
         self.ensure_auth_configured()?;
+        let language = self.resolve_locale(locale).as_tmdb_language();
         let response = self
-            .send_json::<TmdbMovieDetails>(self.get(&format!("/movie/{movie_id}")).query(&[
-                ("append_to_response", "credits,keywords"),
-                ("language", "en-US"),
-            ]))
+            .send_json::<TmdbMovieDetails>(
+                self.get(&format!("/movie/{movie_id}")).query(&[
+                    ("append_to_response", "credits,keywords,external_ids"),
+                    ("language", language.as_str()),
+                ]),
+                LONG_CACHE_TTL,
+            )
             .await?;
 
         let director = response
@@ -145,6 +311,7 @@ impl MovieProvider for TmdbProvider {
 
         let genres = response.genres.unwrap_or_default();
         let genre_ids = genres.iter().map(|genre| genre.id).collect::<Vec<_>>();
+        let imdb_id = response.external_ids.and_then(|ids| ids.imdb_id);
 
         Ok(MovieDetails {
             id: response.id,
@@ -161,55 +328,115 @@ impl MovieProvider for TmdbProvider {
             director_id: director.map(|member| member.id),
             cast_ids,
             genre_ids,
+            imdb_id,
         })
     }
 
-    async fn similar_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError> {
+    async fn similar_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
         self.ensure_auth_configured()?;
+        let page = page.to_string();
+        let region = self.resolved_region(&locale);
+        let language = self.resolve_locale(locale).as_tmdb_language();
+        let mut req = self.get(&format!("/movie/{movie_id}/similar")).query(&[
+            ("language", language.as_str()),
+            ("page", page.as_str()),
+        ]);
+        if let Some(region) = &region {
+            req = req.query(&[("region", region.as_str())]);
+        }
         let response = self
-            .send_json::<TmdbListResponse>(
-                self.get(&format!("/movie/{movie_id}/similar"))
-                    .query(&[("language", "en-US"), ("page", "1")]),
-            )
+            .send_json::<TmdbListResponse>(req, LONG_CACHE_TTL)
             .await?;
-        Ok(response
-            .results
-            .into_iter()
-            .map(|item| SearchMovie {
-                id: item.id,
-                title: item.title,
-                release_date: item.release_date,
-                poster_path: item.poster_path,
-                vote_average: item.vote_average.unwrap_or_default(),
-            })
-            .collect())
+        Ok(response.into_paged())
+    }
+
+    async fn recommended_movies(
+        &self,
+        movie_id: u64,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
+        self.ensure_auth_configured()?;
+        let page = page.to_string();
+        let region = self.resolved_region(&locale);
+        let language = self.resolve_locale(locale).as_tmdb_language();
+        let mut req = self
+            .get(&format!("/movie/{movie_id}/recommendations"))
+            .query(&[("language", language.as_str()), ("page", page.as_str())]);
+        if let Some(region) = &region {
+            req = req.query(&[("region", region.as_str())]);
+        }
+        let response = self
+            .send_json::<TmdbListResponse>(req, LONG_CACHE_TTL)
+            .await?;
+        Ok(response.into_paged())
     }
 
-    async fn recommended_movies(&self, movie_id: u64) -> Result<Vec<SearchMovie>, AppError> {
+    async fn discover_movies(
+        &self,
+        filter: DiscoverFilter,
+        page: u32,
+        locale: Option<Locale>,
+    ) -> Result<Paged<SearchMovie>, AppError> {
         self.ensure_auth_configured()?;
+        let mut query = vec![
+            (
+                "language".to_string(),
+                self.resolve_locale(locale).as_tmdb_language(),
+            ),
+            ("page".to_string(), page.to_string()),
+        ];
+        query.extend(
+            filter
+                .query_pairs()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value)),
+        );
+
         let response = self
             .send_json::<TmdbListResponse>(
-                self.get(&format!("/movie/{movie_id}/recommendations"))
-                    .query(&[("language", "en-US"), ("page", "1")]),
+                self.get("/discover/movie").query(&query),
+                SHORT_CACHE_TTL,
             )
             .await?;
-        Ok(response
-            .results
-            .into_iter()
-            .map(|item| SearchMovie {
-                id: item.id,
-                title: item.title,
-                release_date: item.release_date,
-                poster_path: item.poster_path,
-                vote_average: item.vote_average.unwrap_or_default(),
-            })
-            .collect())
+
+        Ok(response.into_paged())
     }
 }
 
 #[derive(Debug, Deserialize)]
 struct TmdbListResponse {
     results: Vec<TmdbListMovie>,
+    page: u32,
+    total_pages: u32,
+    total_results: u32,
+}
+
+impl TmdbListResponse {
+    fn into_paged(self) -> Paged<SearchMovie> {
+        Paged {
+            results: self
+                .results
+                .into_iter()
+                .map(|item| SearchMovie {
+                    id: item.id,
+                    title: item.title,
+                    release_date: item.release_date,
+                    poster_path: item.poster_path,
+                    vote_average: item.vote_average.unwrap_or_default(),
+                    media_type: crate::MediaType::Movie,
+                })
+                .collect(),
+            page: self.page,
+            total_pages: self.total_pages,
+            total_results: self.total_results,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -233,6 +460,12 @@ struct TmdbMovieDetails {
     backdrop_path: Option<String>,
     vote_average: Option<f64>,
     credits: Option<TmdbCredits>,
+    external_ids: Option<TmdbExternalIds>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbExternalIds {
+    imdb_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -248,6 +481,330 @@ struct TmdbCast {
     character: Option<String>,
 }
 
+#[async_trait]
+impl crate::TvProvider for TmdbProvider {
+    async fn search_tv(
+        &self,
+        query: &str,
+        page: u32,
+    ) -> Result<Paged<crate::SearchTvShow>, AppError> {
+        self.ensure_auth_configured()?;
+        let page = page.to_string();
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvListResponse>(
+                self.get("/search/tv").query(&[
+                    ("query", query),
+                    ("include_adult", "false"),
+                    ("language", language.as_str()),
+                    ("page", page.as_str()),
+                ]),
+                SHORT_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_SHOW_NOT_FOUND", "TV show not found"))?;
+        Ok(response.into_paged())
+    }
+
+    async fn tv_details(&self, tv_id: u64) -> Result<crate::TvShow, AppError> {
+        self.ensure_auth_configured()?;
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvDetails>(
+                self.get(&format!("/tv/{tv_id}")).query(&[
+                    ("append_to_response", "credits,keywords"),
+                    ("language", language.as_str()),
+                ]),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_SHOW_NOT_FOUND", "TV show not found"))?;
+
+        let cast = response
+            .credits
+            .as_ref()
+            .and_then(|credits| credits.cast.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .take(10)
+            .map(|member| CastMember {
+                id: member.id,
+                name: member.name,
+                character: member.character,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(crate::TvShow {
+            id: response.id,
+            name: response.name,
+            overview: response.overview,
+            first_air_date: response.first_air_date,
+            number_of_seasons: response.number_of_seasons,
+            number_of_episodes: response.number_of_episodes,
+            episode_run_time: response.episode_run_time.unwrap_or_default(),
+            genres: response.genres.unwrap_or_default(),
+            poster_path: response.poster_path,
+            backdrop_path: response.backdrop_path,
+            vote_average: response.vote_average.unwrap_or_default(),
+            cast,
+        })
+    }
+
+    async fn tv_season(
+        &self,
+        tv_id: u64,
+        season_number: u32,
+    ) -> Result<crate::TvSeason, AppError> {
+        self.ensure_auth_configured()?;
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvSeason>(
+                self.get(&format!("/tv/{tv_id}/season/{season_number}"))
+                    .query(&[("language", language.as_str())]),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_SEASON_NOT_FOUND", "TV season not found"))?;
+
+        Ok(crate::TvSeason {
+            id: response.id,
+            season_number: response.season_number,
+            name: response.name,
+            overview: response.overview,
+            air_date: response.air_date,
+            episodes: response
+                .episodes
+                .unwrap_or_default()
+                .into_iter()
+                .map(TmdbTvEpisode::into_episode)
+                .collect(),
+        })
+    }
+
+    async fn tv_episode(
+        &self,
+        tv_id: u64,
+        season_number: u32,
+        episode_number: u32,
+    ) -> Result<crate::TvEpisode, AppError> {
+        self.ensure_auth_configured()?;
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvEpisode>(
+                self.get(&format!(
+                    "/tv/{tv_id}/season/{season_number}/episode/{episode_number}"
+                ))
+                .query(&[("language", language.as_str())]),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_EPISODE_NOT_FOUND", "TV episode not found"))?;
+        Ok(response.into_episode())
+    }
+
+    async fn similar_tv(
+        &self,
+        tv_id: u64,
+        page: u32,
+    ) -> Result<Paged<crate::SearchTvShow>, AppError> {
+        self.ensure_auth_configured()?;
+        let page = page.to_string();
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvListResponse>(
+                self.get(&format!("/tv/{tv_id}/similar"))
+                    .query(&[("language", language.as_str()), ("page", page.as_str())]),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_SHOW_NOT_FOUND", "TV show not found"))?;
+        Ok(response.into_paged())
+    }
+
+    async fn recommended_tv(
+        &self,
+        tv_id: u64,
+        page: u32,
+    ) -> Result<Paged<crate::SearchTvShow>, AppError> {
+        self.ensure_auth_configured()?;
+        let page = page.to_string();
+        let language = self.resolve_locale(None).as_tmdb_language();
+        let response = self
+            .send_json::<TmdbTvListResponse>(
+                self.get(&format!("/tv/{tv_id}/recommendations"))
+                    .query(&[("language", language.as_str()), ("page", page.as_str())]),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "TV_SHOW_NOT_FOUND", "TV show not found"))?;
+        Ok(response.into_paged())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbTvListResponse {
+    results: Vec<TmdbTvListItem>,
+    page: u32,
+    total_pages: u32,
+    total_results: u32,
+}
+
+impl TmdbTvListResponse {
+    fn into_paged(self) -> Paged<crate::SearchTvShow> {
+        Paged {
+            results: self
+                .results
+                .into_iter()
+                .map(|item| crate::SearchTvShow {
+                    id: item.id,
+                    name: item.name,
+                    first_air_date: item.first_air_date,
+                    poster_path: item.poster_path,
+                    vote_average: item.vote_average.unwrap_or_default(),
+                    media_type: crate::MediaType::Tv,
+                })
+                .collect(),
+            page: self.page,
+            total_pages: self.total_pages,
+            total_results: self.total_results,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbTvListItem {
+    id: u64,
+    name: String,
+    first_air_date: Option<String>,
+    poster_path: Option<String>,
+    vote_average: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbTvDetails {
+    id: u64,
+    name: String,
+    overview: Option<String>,
+    first_air_date: Option<String>,
+    number_of_seasons: Option<u32>,
+    number_of_episodes: Option<u32>,
+    episode_run_time: Option<Vec<u32>>,
+    genres: Option<Vec<Genre>>,
+    poster_path: Option<String>,
+    backdrop_path: Option<String>,
+    vote_average: Option<f64>,
+    credits: Option<TmdbCredits>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbTvSeason {
+    id: u64,
+    season_number: u32,
+    name: String,
+    overview: Option<String>,
+    air_date: Option<String>,
+    episodes: Option<Vec<TmdbTvEpisode>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbTvEpisode {
+    id: u64,
+    episode_number: u32,
+    season_number: u32,
+    name: String,
+    overview: Option<String>,
+    air_date: Option<String>,
+    vote_average: Option<f64>,
+}
+
+impl TmdbTvEpisode {
+    fn into_episode(self) -> crate::TvEpisode {
+        crate::TvEpisode {
+            id: self.id,
+            episode_number: self.episode_number,
+            season_number: self.season_number,
+            name: self.name,
+            overview: self.overview,
+            air_date: self.air_date,
+            vote_average: self.vote_average.unwrap_or_default(),
+        }
+    }
+}
+
+#[async_trait]
+impl crate::WatchProvider for TmdbProvider {
+    async fn watch_providers(&self, movie_id: u64) -> Result<crate::WatchAvailability, AppError> {
+        self.ensure_auth_configured()?;
+        let response = self
+            .send_json::<TmdbWatchProvidersResponse>(
+                self.get(&format!("/movie/{movie_id}/watch/providers")),
+                LONG_CACHE_TTL,
+            )
+            .await
+            .map_err(|err| remap_not_found(err, "MOVIE_NOT_FOUND", "Movie not found"))?;
+
+        Ok(response
+            .results
+            .into_iter()
+            .map(|(country, options)| (country, options.into_watch_options()))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbWatchProvidersResponse {
+    results: std::collections::HashMap<String, TmdbWatchOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbWatchOptions {
+    flatrate: Option<Vec<TmdbWatchService>>,
+    rent: Option<Vec<TmdbWatchService>>,
+    buy: Option<Vec<TmdbWatchService>>,
+}
+
+impl TmdbWatchOptions {
+    fn into_watch_options(self) -> crate::WatchOptions {
+        crate::WatchOptions {
+            flatrate: self
+                .flatrate
+                .unwrap_or_default()
+                .into_iter()
+                .map(TmdbWatchService::into_service)
+                .collect(),
+            rent: self
+                .rent
+                .unwrap_or_default()
+                .into_iter()
+                .map(TmdbWatchService::into_service)
+                .collect(),
+            buy: self
+                .buy
+                .unwrap_or_default()
+                .into_iter()
+                .map(TmdbWatchService::into_service)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TmdbWatchService {
+    provider_id: u64,
+    provider_name: String,
+    logo_path: Option<String>,
+}
+
+impl TmdbWatchService {
+    fn into_service(self) -> crate::WatchService {
+        crate::WatchService {
+            provider_id: self.provider_id,
+            provider_name: self.provider_name,
+            logo_path: self.logo_path,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct TmdbCrew {
     id: u64,
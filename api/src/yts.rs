@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{AppError, Torrent, TorrentProvider};
+
+/// Torrent availability backed by the YTS public movie index, keyed on
+/// IMDb id (YTS calls it `imdb_code`).
+pub struct YtsProvider {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl YtsProvider {
+    pub fn new(base_url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+        }
+    }
+}
+
+#[async_trait]
+impl TorrentProvider for YtsProvider {
+    async fn torrents_for_imdb_id(&self, imdb_id: &str) -> Result<Vec<Torrent>, AppError> {
+        let url = format!(
+            "{}/api/v2/list_movies.json",
+            self.base_url.trim_end_matches('/')
+        );
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("query_term", imdb_id)])
+            .send()
+            .await
+            .map_err(|_| AppError::upstream("YTS_REQUEST_FAILED", "Failed to reach YTS"))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::upstream(
+                "YTS_UPSTREAM_ERROR",
+                "YTS returned a non-success response",
+            ));
+        }
+
+        let body: YtsListMoviesResponse = response
+            .json()
+            .await
+            .map_err(|_| AppError::upstream("YTS_DECODE_FAILED", "Failed to decode YTS response"))?;
+
+        Ok(body
+            .data
+            .into_movies()
+            .into_iter()
+            .find(|movie| movie.imdb_code.eq_ignore_ascii_case(imdb_id))
+            .and_then(|movie| movie.torrents)
+            .unwrap_or_default()
+            .into_iter()
+            .map(YtsTorrent::into_torrent)
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct YtsListMoviesResponse {
+    data: YtsData,
+}
+
+/// YTS returns `data` as an object with a `movies` list on success, but as
+/// an empty array on some error responses; accept either shape rather than
+/// failing decode and surfacing `YTS_DECODE_FAILED` for what is really "no
+/// results".
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum YtsData {
+    Found { movies: Option<Vec<YtsListMovie>> },
+    Empty(Vec<serde::de::IgnoredAny>),
+}
+
+impl YtsData {
+    fn into_movies(self) -> Vec<YtsListMovie> {
+        match self {
+            YtsData::Found { movies } => movies.unwrap_or_default(),
+            YtsData::Empty(_) => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct YtsListMovie {
+    imdb_code: String,
+    torrents: Option<Vec<YtsTorrent>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtsTorrent {
+    quality: String,
+    size: String,
+    seeds: u32,
+    peers: u32,
+    url: String,
+}
+
+impl YtsTorrent {
+    fn into_torrent(self) -> Torrent {
+        Torrent {
+            quality: self.quality,
+            size: self.size,
+            seeds: self.seeds,
+            peers: self.peers,
+            url: self.url,
+        }
+    }
+}